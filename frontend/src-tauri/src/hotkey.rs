@@ -0,0 +1,111 @@
+use std::sync::Mutex;
+
+use tauri::Manager;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_plugin_store::StoreExt;
+
+use crate::focus_main_window;
+
+const DEFAULT_SHORTCUT: &str = "CmdOrCtrl+Shift+A";
+const STORE_KEY: &str = "global_shortcut";
+
+/// The accelerator that's actually registered with the OS right now, as
+/// opposed to whatever is persisted in the store — the two can diverge if a
+/// persisted accelerator fails to register (OS conflict, no-longer-valid
+/// string) and we silently fall back to the default.
+/// `None` until the first successful `register()`, so startup can tell
+/// "nothing registered yet" apart from "already registered as the default".
+pub struct ActiveShortcut(Mutex<Option<String>>);
+
+impl Default for ActiveShortcut {
+    fn default() -> Self {
+        Self(Mutex::new(None))
+    }
+}
+
+/// Registers the persisted accelerator (or the default) on startup. Falls
+/// back to the default if the persisted one is no longer valid, e.g. the OS
+/// grabbed it in the meantime, and persists that fallback so the store and
+/// the frontend both reflect what's actually active.
+pub fn register_default(app: &tauri::AppHandle) {
+    let accelerator = saved_shortcut(app).unwrap_or_else(|| DEFAULT_SHORTCUT.to_string());
+    if register(app, &accelerator).is_ok() {
+        return;
+    }
+
+    if register(app, DEFAULT_SHORTCUT).is_ok() && accelerator != DEFAULT_SHORTCUT {
+        persist_shortcut(app, DEFAULT_SHORTCUT);
+    }
+}
+
+fn saved_shortcut(app: &tauri::AppHandle) -> Option<String> {
+    let store = app.store("settings.json").ok()?;
+    store.get(STORE_KEY)?.as_str().map(|s| s.to_string())
+}
+
+fn persist_shortcut(app: &tauri::AppHandle, accelerator: &str) {
+    if let Ok(store) = app.store("settings.json") {
+        store.set(STORE_KEY, serde_json::json!(accelerator));
+        let _ = store.save();
+    }
+}
+
+/// Registers `accelerator`, only dropping whatever was previously registered
+/// once the new one is confirmed — so a rejected accelerator (OS conflict,
+/// taken by another app) leaves the existing working binding intact instead
+/// of leaving the user with no hotkey at all.
+fn register(app: &tauri::AppHandle, accelerator: &str) -> Result<(), String> {
+    let shortcut: Shortcut = accelerator
+        .parse()
+        .map_err(|_| format!("\"{}\" is not a valid shortcut", accelerator))?;
+
+    let previous = app
+        .try_state::<ActiveShortcut>()
+        .and_then(|state| state.0.lock().ok().and_then(|s| s.clone()));
+
+    if previous.as_deref() == Some(accelerator) {
+        return Ok(());
+    }
+
+    app.global_shortcut()
+        .on_shortcut(shortcut, |app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                focus_main_window(app);
+            }
+        })
+        .map_err(|e| format!("\"{}\" could not be registered: {}", accelerator, e))?;
+
+    if let Some(previous) = previous {
+        if let Ok(previous_shortcut) = previous.parse::<Shortcut>() {
+            let _ = app.global_shortcut().unregister(previous_shortcut);
+        }
+    }
+
+    if let Some(active) = app.try_state::<ActiveShortcut>() {
+        if let Ok(mut current) = active.0.lock() {
+            *current = Some(accelerator.to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Re-registers the global shortcut to `accelerator`, persists it, and
+/// reports a clear error if the accelerator is malformed or already taken
+/// by the OS or another application.
+#[tauri::command]
+pub fn set_global_shortcut(app: tauri::AppHandle, accelerator: String) -> Result<(), String> {
+    register(&app, &accelerator)?;
+    persist_shortcut(&app, &accelerator);
+    Ok(())
+}
+
+/// Returns the accelerator that's actually registered right now, which may
+/// differ from the persisted one if it failed to register at startup. Falls
+/// back to the default accelerator's string when nothing is registered.
+#[tauri::command]
+pub fn get_global_shortcut(app: tauri::AppHandle) -> Result<String, String> {
+    let active = app
+        .try_state::<ActiveShortcut>()
+        .and_then(|state| state.0.lock().ok().and_then(|s| s.clone()));
+    Ok(active.unwrap_or_else(|| DEFAULT_SHORTCUT.to_string()))
+}
@@ -1,37 +1,33 @@
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
 use tauri::{Manager, RunEvent, State};
 use tauri_plugin_autostart::MacosLauncher;
 
+mod hotkey;
 mod server;
+mod updater;
 
+use server::{ServiceKind, ServiceRegistry};
+
+#[derive(Default)]
 pub struct AppState {
-    pub server_running: bool,
-    pub server_port: u16,
-    pub api_url: String,
-    pub mcp_running: bool,
-    pub mcp_port: u16,
-    pub mcp_url: String,
+    pub server_running: AtomicBool,
+    pub mcp_running: AtomicBool,
 }
 
 #[tauri::command]
-fn get_server_status(state: State<'_, Mutex<AppState>>) -> Result<bool, String> {
-    let s = state.lock().map_err(|e| e.to_string())?;
-    Ok(s.server_running)
+fn get_server_status(state: State<'_, AppState>) -> Result<bool, String> {
+    Ok(state.server_running.load(Ordering::SeqCst))
 }
 
 #[tauri::command]
-fn get_api_url(state: State<'_, Mutex<AppState>>) -> Result<String, String> {
-    let s = state.lock().map_err(|e| e.to_string())?;
-    Ok(s.api_url.clone())
+fn get_api_url(registry: State<'_, ServiceRegistry>) -> Result<String, String> {
+    Ok(registry.base_url(ServiceKind::Backend))
 }
 
 #[tauri::command]
-async fn check_server_health(state: State<'_, Mutex<AppState>>) -> Result<bool, String> {
-    let url = {
-        let s = state.lock().map_err(|e| e.to_string())?;
-        format!("{}/health", s.api_url)
-    };
+async fn check_server_health(registry: State<'_, ServiceRegistry>) -> Result<bool, String> {
+    let url = format!("{}/health", registry.base_url(ServiceKind::Backend));
     match reqwest::get(&url).await {
         Ok(resp) => Ok(resp.status().is_success()),
         Err(_) => Ok(false),
@@ -58,8 +54,23 @@ fn stop_mcp_server(app: tauri::AppHandle) -> Result<(), String> {
     server::do_stop_mcp(&app)
 }
 
+/// Shows and focuses the main window; shared by the tray click handler, the
+/// macOS dock reopen event, and a second launch handed off by the
+/// single-instance plugin.
+pub(crate) fn focus_main_window(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        window.show().unwrap_or_default();
+        window.set_focus().unwrap_or_default();
+    }
+}
+
 pub fn run() {
     tauri::Builder::default()
+        .plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
+            // A second launch means the user wants the existing window back,
+            // not a duplicate backend/MCP supervisor fighting over ports.
+            focus_main_window(app);
+        }))
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_store::Builder::default().build())
         .plugin(tauri_plugin_dialog::init())
@@ -69,16 +80,11 @@ pub fn run() {
             Some(vec!["--hidden"]),
         ))
         .plugin(tauri_plugin_process::init())
-        .manage(Mutex::new(AppState {
-            server_running: false,
-            server_port: 8900,
-            api_url: "http://127.0.0.1:8900".to_string(),
-            mcp_running: false,
-            mcp_port: 8901,
-            mcp_url: "http://127.0.0.1:8901".to_string(),
-        }))
-        .manage(Mutex::new(server::ServerProcess { child: None }))
-        .manage(Mutex::new(server::McpProcess { child: None }))
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .manage(AppState::default())
+        .manage(ServiceRegistry::default())
+        .manage(hotkey::ActiveShortcut::default())
         .invoke_handler(tauri::generate_handler![
             get_server_status,
             get_api_url,
@@ -87,6 +93,12 @@ pub fn run() {
             stop_server,
             start_mcp_server,
             stop_mcp_server,
+            server::set_autorestart,
+            server::set_mcp_autorestart,
+            updater::check_for_update,
+            updater::install_update,
+            hotkey::set_global_shortcut,
+            hotkey::get_global_shortcut,
         ])
         .setup(|app| {
             // Create tray icon with click handler to show/focus window
@@ -101,17 +113,17 @@ pub fn run() {
                         ..
                     } = event
                     {
-                        let app = tray.app_handle();
-                        if let Some(window) = app.get_webview_window("main") {
-                            window.show().unwrap_or_default();
-                            window.set_focus().unwrap_or_default();
-                        }
+                        focus_main_window(tray.app_handle());
                     }
                 })
                 .build(app)?;
 
+            server::load_persisted_settings(app.handle());
+            hotkey::register_default(app.handle());
+
             let app_handle = app.handle().clone();
             let mcp_handle = app.handle().clone();
+            let updater_handle = app.handle().clone();
             // Auto-start backend server
             tauri::async_runtime::spawn(async move {
                 server::start_backend_server(app_handle).await;
@@ -121,6 +133,11 @@ pub fn run() {
                 tokio::time::sleep(std::time::Duration::from_secs(2)).await;
                 let _ = server::do_start_mcp(&mcp_handle).await;
             });
+            // Check for an app update once the backend has had a chance to settle
+            tauri::async_runtime::spawn(async move {
+                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                updater::check_on_startup(updater_handle).await;
+            });
             Ok(())
         })
         .on_window_event(|window, event| {
@@ -136,10 +153,7 @@ pub fn run() {
             match event {
                 RunEvent::Reopen { .. } => {
                     // macOS: clicking dock icon should show the window
-                    if let Some(window) = app_handle.get_webview_window("main") {
-                        window.show().unwrap_or_default();
-                        window.set_focus().unwrap_or_default();
-                    }
+                    focus_main_window(app_handle);
                 }
                 RunEvent::ExitRequested { api, .. } => {
                     // Keep running in background when window is closed
@@ -1,27 +1,177 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU32, Ordering};
 use std::sync::Mutex;
+use std::time::Duration;
+
 use tauri::{Emitter, Manager};
+use tauri_plugin_shell::process::{CommandChild, CommandEvent};
 use tauri_plugin_shell::ShellExt;
-use tauri_plugin_shell::process::CommandChild;
+use tauri_plugin_store::StoreExt;
 
 use crate::AppState;
 
-/// Holds the running server child process
-pub struct ServerProcess {
-    pub child: Option<CommandChild>,
+/// Initial restart delay after an unexpected crash; doubles on each
+/// consecutive crash up to `MAX_BACKOFF_SECS`.
+const BASE_BACKOFF_SECS: u64 = 1;
+const MAX_BACKOFF_SECS: u64 = 30;
+/// How long a restarted service has to stay healthy before we reset its
+/// backoff counter back to the base delay.
+const STABLE_WINDOW_SECS: u64 = 60;
+/// How many ports past the preferred one we're willing to try before giving up.
+const PORT_SCAN_RANGE: u16 = 50;
+
+/// The services this app supervises. Add a variant here (and to
+/// `ServiceKind::ALL`) to bring a new child process under the same
+/// start/stop/health/restart machinery.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ServiceKind {
+    Backend,
+    Mcp,
 }
 
-/// Holds the running MCP server child process
-pub struct McpProcess {
-    pub child: Option<CommandChild>,
+impl ServiceKind {
+    const ALL: [ServiceKind; 2] = [ServiceKind::Backend, ServiceKind::Mcp];
+
+    fn default_port(self) -> u16 {
+        match self {
+            ServiceKind::Backend => 8900,
+            ServiceKind::Mcp => 8901,
+        }
+    }
+
+    /// Path appended to the service's base URL to probe whether it's alive.
+    fn health_path(self) -> &'static str {
+        match self {
+            ServiceKind::Backend => "/health",
+            ServiceKind::Mcp => "/sse",
+        }
+    }
+
+    fn log_event(self) -> &'static str {
+        match self {
+            ServiceKind::Backend => "server-log",
+            ServiceKind::Mcp => "mcp-log",
+        }
+    }
+
+    fn status_event(self) -> &'static str {
+        match self {
+            ServiceKind::Backend => "server-status",
+            ServiceKind::Mcp => "mcp-status",
+        }
+    }
+
+    /// The python module invocation used to run this service outside of a
+    /// bundled sidecar, e.g. as a fallback when the sidecar binary is missing.
+    fn python_args(self, port: u16) -> Vec<String> {
+        match self {
+            ServiceKind::Backend => vec![
+                "-m".into(),
+                "uvicorn".into(),
+                "achilles.main:app".into(),
+                "--host".into(),
+                "127.0.0.1".into(),
+                "--port".into(),
+                port.to_string(),
+            ],
+            ServiceKind::Mcp => vec![
+                "-m".into(),
+                "achilles.mcp_server".into(),
+                "--port".into(),
+                port.to_string(),
+                "--host".into(),
+                "127.0.0.1".into(),
+                "--transport".into(),
+                "sse".into(),
+            ],
+        }
+    }
+
+    fn base_url(self, port: u16) -> String {
+        format!("http://127.0.0.1:{}", port)
+    }
+
+    fn set_running(self, app: &tauri::AppHandle, running: bool) {
+        if let Some(state) = app.try_state::<AppState>() {
+            match self {
+                ServiceKind::Backend => state.server_running.store(running, Ordering::SeqCst),
+                ServiceKind::Mcp => state.mcp_running.store(running, Ordering::SeqCst),
+            }
+        }
+    }
+
+    fn autorestart_key(self) -> &'static str {
+        match self {
+            ServiceKind::Backend => "server_autorestart",
+            ServiceKind::Mcp => "mcp_autorestart",
+        }
+    }
+}
+
+/// Per-service state: the child process, the port it's bound to, and the
+/// restart bookkeeping the supervisor needs.
+pub struct ServiceEntry {
+    child: Mutex<Option<CommandChild>>,
+    port: AtomicU16,
+    /// Cleared by an explicit stop so the supervisor can tell a `Terminated`
+    /// event was requested, not a crash.
+    should_run: AtomicBool,
+    autorestart: AtomicBool,
+    restart_attempts: AtomicU32,
+    /// Bumped by every explicit `stop()`. A `start()`/`start_sidecar` call
+    /// snapshots this before spawning; if it's moved on by the time the
+    /// child comes up, a stop raced the spawn and wins — the new child gets
+    /// killed instead of silently resurrecting a service the user just
+    /// asked to stop.
+    stop_generation: AtomicU32,
+}
+
+impl ServiceEntry {
+    fn new(port: u16) -> Self {
+        Self {
+            child: Mutex::new(None),
+            port: AtomicU16::new(port),
+            should_run: AtomicBool::new(false),
+            autorestart: AtomicBool::new(true),
+            restart_attempts: AtomicU32::new(0),
+            stop_generation: AtomicU32::new(0),
+        }
+    }
+
+    fn port(&self) -> u16 {
+        self.port.load(Ordering::SeqCst)
+    }
+}
+
+/// Holds every supervised child process, keyed by `ServiceKind`.
+pub struct ServiceRegistry {
+    entries: HashMap<ServiceKind, ServiceEntry>,
+}
+
+impl Default for ServiceRegistry {
+    fn default() -> Self {
+        let entries = ServiceKind::ALL
+            .into_iter()
+            .map(|kind| (kind, ServiceEntry::new(kind.default_port())))
+            .collect();
+        Self { entries }
+    }
+}
+
+impl ServiceRegistry {
+    fn entry(&self, kind: ServiceKind) -> &ServiceEntry {
+        self.entries.get(&kind).expect("every ServiceKind is registered")
+    }
+
+    pub fn base_url(&self, kind: ServiceKind) -> String {
+        kind.base_url(self.entry(kind).port())
+    }
 }
 
 pub async fn start_backend_server(app: tauri::AppHandle) {
     // Try to use the bundled sidecar first, fall back to system python
-    let sidecar_ok = start_sidecar(&app).await;
-
-    if !sidecar_ok {
-        // Fallback: try running with system python
-        start_with_python(&app).await;
+    if !start_sidecar(&app).await {
+        let _ = start(&app, ServiceKind::Backend).await;
     }
 }
 
@@ -32,190 +182,358 @@ async fn start_sidecar(app: &tauri::AppHandle) -> bool {
         Err(_) => return false,
     };
 
+    let kind = ServiceKind::Backend;
+    let port = resolve_port(app, kind).await;
+    let sidecar = sidecar.args(["--port", &port.to_string()]);
+    let generation = stop_generation(app, kind);
+
     match sidecar.spawn() {
-        Ok((_rx, child)) => {
-            // Store the child process
-            if let Some(state) = app.try_state::<Mutex<ServerProcess>>() {
-                if let Ok(mut proc) = state.lock() {
-                    proc.child = Some(child);
-                }
+        Ok((rx, child)) => {
+            if !store_child(app, kind, child, generation) {
+                return false;
             }
+            supervise(app.clone(), kind, rx);
 
-            // Wait a moment then check health
-            tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+            tokio::time::sleep(Duration::from_secs(3)).await;
 
-            if check_health("http://127.0.0.1:8900").await {
-                update_state(app, true);
-                app.emit("server-status", "running").unwrap_or_default();
+            if health(kind, port).await {
+                kind.set_running(app, true);
+                app.emit(kind.status_event(), "running").unwrap_or_default();
                 return true;
             }
+            // The sidecar came up but never passed its health check within
+            // the window above. Tear it down and clear the registry entry
+            // before signaling failure, so the python fallback that's about
+            // to spawn doesn't leave this one behind as an orphaned,
+            // unstoppable second process for the same service.
+            kill_and_clear(app, kind);
             false
         }
         Err(_) => false,
     }
 }
 
-async fn start_with_python(app: &tauri::AppHandle) {
-    let shell = app.shell();
-
-    // Try multiple Python executables
-    let python_cmds = ["python3", "python"];
-
-    for python_cmd in &python_cmds {
-        let result = shell
-            .command(python_cmd)
-            .args(["-m", "uvicorn", "achilles.main:app", "--host", "127.0.0.1", "--port", "8900"])
-            .spawn();
-
-        match result {
-            Ok((_rx, child)) => {
-                // Store the child process
-                if let Some(state) = app.try_state::<Mutex<ServerProcess>>() {
-                    if let Ok(mut proc) = state.lock() {
-                        proc.child = Some(child);
-                    }
-                }
-
-                // Wait for server to start
-                for _ in 0..15 {
-                    tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-                    if check_health("http://127.0.0.1:8900").await {
-                        update_state(app, true);
-                        app.emit("server-status", "running").unwrap_or_default();
-                        return;
-                    }
-                }
-                // This python command was found but server didn't start, continue trying
+/// Kills and forgets whatever child is currently stored for `kind`. Marks
+/// the service as not-should-run first so the `Terminated` event this
+/// produces is treated as an intentional stop, not a crash to restart from.
+fn kill_and_clear(app: &tauri::AppHandle, kind: ServiceKind) {
+    if let Some(registry) = app.try_state::<ServiceRegistry>() {
+        let entry = registry.entry(kind);
+        entry.should_run.store(false, Ordering::SeqCst);
+        entry.stop_generation.fetch_add(1, Ordering::SeqCst);
+        if let Ok(mut child) = entry.child.lock() {
+            if let Some(child) = child.take() {
+                let _ = child.kill();
             }
-            Err(_) => continue,
         }
     }
-
-    app.emit("server-status", "failed").unwrap_or_default();
+    kind.set_running(app, false);
 }
 
-pub async fn do_start_server(app: &tauri::AppHandle) -> Result<(), String> {
-    // Check if already running
-    if check_health("http://127.0.0.1:8900").await {
-        update_state(app, true);
+/// Spawns `kind` via `python3`/`python`, waiting up to `poll_secs` for it to
+/// become healthy before giving up on this python executable.
+async fn start(app: &tauri::AppHandle, kind: ServiceKind) -> Result<(), String> {
+    let port = resolve_port(app, kind).await;
+
+    if health(kind, port).await {
+        kind.set_running(app, true);
         return Ok(());
     }
 
     let shell = app.shell();
     let python_cmds = ["python3", "python"];
+    let args = kind.python_args(port);
+    let poll_secs = match kind {
+        ServiceKind::Backend => 15,
+        ServiceKind::Mcp => 10,
+    };
 
     for python_cmd in &python_cmds {
-        let result = shell
-            .command(python_cmd)
-            .args(["-m", "uvicorn", "achilles.main:app", "--host", "127.0.0.1", "--port", "8900"])
-            .spawn();
+        // Captured fresh each attempt: `kill_and_clear` below bumps this, so
+        // reusing a generation from an earlier loop iteration would make the
+        // next `store_child` think its own cleanup was a concurrent stop.
+        let generation = stop_generation(app, kind);
+        let result = shell.command(python_cmd).args(&args).spawn();
 
         match result {
-            Ok((_rx, child)) => {
-                if let Some(state) = app.try_state::<Mutex<ServerProcess>>() {
-                    if let Ok(mut proc) = state.lock() {
-                        proc.child = Some(child);
+            Ok((rx, child)) => {
+                if !store_child(app, kind, child, generation) {
+                    return Ok(());
+                }
+                supervise(app.clone(), kind, rx);
+
+                let mut became_healthy = false;
+                for _ in 0..poll_secs {
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    if health(kind, port).await {
+                        kind.set_running(app, true);
+                        app.emit(kind.status_event(), "running").unwrap_or_default();
+                        became_healthy = true;
+                        break;
                     }
                 }
-                return Ok(());
+                if became_healthy {
+                    return Ok(());
+                }
+
+                // This python command spawned but the service never passed
+                // its health check in time; tear it down before trying the
+                // next one so we don't leave it running as an orphan.
+                kill_and_clear(app, kind);
+                continue;
             }
             Err(_) => continue,
         }
     }
 
-    Err("Could not find python3 or python. Please install Python 3.11+.".to_string())
+    app.emit(kind.status_event(), "failed").unwrap_or_default();
+    Err(format!(
+        "Could not find a working python3 or python to start the {:?} service.",
+        kind
+    ))
 }
 
-pub fn do_stop_server(app: &tauri::AppHandle) -> Result<(), String> {
-    if let Some(state) = app.try_state::<Mutex<ServerProcess>>() {
-        if let Ok(mut proc) = state.lock() {
-            if let Some(child) = proc.child.take() {
+fn stop(app: &tauri::AppHandle, kind: ServiceKind) -> Result<(), String> {
+    if let Some(registry) = app.try_state::<ServiceRegistry>() {
+        let entry = registry.entry(kind);
+        entry.should_run.store(false, Ordering::SeqCst);
+        entry.stop_generation.fetch_add(1, Ordering::SeqCst);
+        if let Ok(mut child) = entry.child.lock() {
+            if let Some(child) = child.take() {
                 child.kill().map_err(|e| e.to_string())?;
             }
         }
     }
-    update_state(app, false);
-    app.emit("server-status", "stopped").unwrap_or_default();
+    kind.set_running(app, false);
+    app.emit(kind.status_event(), "stopped").unwrap_or_default();
     Ok(())
 }
 
-async fn check_health(base_url: &str) -> bool {
-    let url = format!("{}/health", base_url);
-    match reqwest::get(&url).await {
-        Ok(resp) => resp.status().is_success(),
-        Err(_) => false,
-    }
+fn stop_generation(app: &tauri::AppHandle, kind: ServiceKind) -> u32 {
+    app.try_state::<ServiceRegistry>()
+        .map(|registry| registry.entry(kind).stop_generation.load(Ordering::SeqCst))
+        .unwrap_or(0)
 }
 
-fn update_state(app: &tauri::AppHandle, running: bool) {
-    if let Some(state) = app.try_state::<Mutex<AppState>>() {
-        if let Ok(mut s) = state.lock() {
-            s.server_running = running;
-        }
+/// Stores a freshly spawned `child` for `kind`, unless an explicit `stop()`
+/// happened after `generation` was captured — in that case this spawn raced
+/// a user-initiated stop, so the new child is killed instead of being
+/// allowed to resurrect a service the user just asked to stop. Returns
+/// whether the child was kept.
+fn store_child(app: &tauri::AppHandle, kind: ServiceKind, child: CommandChild, generation: u32) -> bool {
+    let Some(registry) = app.try_state::<ServiceRegistry>() else {
+        return false;
+    };
+    let entry = registry.entry(kind);
+    if entry.stop_generation.load(Ordering::SeqCst) != generation {
+        let _ = child.kill();
+        return false;
+    }
+    if let Ok(mut slot) = entry.child.lock() {
+        *slot = Some(child);
     }
+    entry.should_run.store(true, Ordering::SeqCst);
+    true
 }
 
-// --- MCP Server Management ---
+pub async fn do_start_server(app: &tauri::AppHandle) -> Result<(), String> {
+    start(app, ServiceKind::Backend).await
+}
+
+pub fn do_stop_server(app: &tauri::AppHandle) -> Result<(), String> {
+    stop(app, ServiceKind::Backend)
+}
 
 pub async fn do_start_mcp(app: &tauri::AppHandle) -> Result<(), String> {
-    // Check if already running
-    if check_mcp_port().await {
-        update_mcp_state(app, true);
-        return Ok(());
-    }
+    start(app, ServiceKind::Mcp).await
+}
 
-    let shell = app.shell();
-    let python_cmds = ["python3", "python"];
+pub fn do_stop_mcp(app: &tauri::AppHandle) -> Result<(), String> {
+    stop(app, ServiceKind::Mcp)
+}
 
-    for python_cmd in &python_cmds {
-        let result = shell
-            .command(python_cmd)
-            .args(["-m", "achilles.mcp_server", "--port", "8901", "--host", "127.0.0.1", "--transport", "sse"])
-            .spawn();
+/// Marks whether the backend should be kept alive by the auto-restart policy.
+#[tauri::command]
+pub fn set_autorestart(app: tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    set_service_autorestart(&app, ServiceKind::Backend, enabled)
+}
 
-        match result {
-            Ok((_rx, child)) => {
-                if let Some(state) = app.try_state::<Mutex<McpProcess>>() {
-                    if let Ok(mut proc) = state.lock() {
-                        proc.child = Some(child);
-                    }
-                }
+/// Marks whether the MCP server should be kept alive by the auto-restart policy.
+#[tauri::command]
+pub fn set_mcp_autorestart(app: tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    set_service_autorestart(&app, ServiceKind::Mcp, enabled)
+}
+
+fn set_service_autorestart(app: &tauri::AppHandle, kind: ServiceKind, enabled: bool) -> Result<(), String> {
+    if let Some(registry) = app.try_state::<ServiceRegistry>() {
+        registry.entry(kind).autorestart.store(enabled, Ordering::SeqCst);
+    }
+    if let Ok(store) = app.store("settings.json") {
+        store.set(kind.autorestart_key(), serde_json::json!(enabled));
+        let _ = store.save();
+    }
+    Ok(())
+}
+
+/// Restores the autorestart flags persisted by `set_autorestart`/
+/// `set_mcp_autorestart`. Call once during setup, after the registry has
+/// been `.manage()`d, so a relaunch doesn't silently discard the user's
+/// choice to disable auto-restart.
+pub fn load_persisted_settings(app: &tauri::AppHandle) {
+    let Ok(store) = app.store("settings.json") else {
+        return;
+    };
+    let Some(registry) = app.try_state::<ServiceRegistry>() else {
+        return;
+    };
+    for kind in ServiceKind::ALL {
+        if let Some(enabled) = store.get(kind.autorestart_key()).and_then(|v| v.as_bool()) {
+            registry.entry(kind).autorestart.store(enabled, Ordering::SeqCst);
+        }
+    }
+}
 
-                // Wait for MCP server to start (poll up to 10 seconds)
-                for _ in 0..10 {
-                    tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-                    if check_mcp_port().await {
-                        update_mcp_state(app, true);
-                        app.emit("mcp-status", "running").unwrap_or_default();
-                        return Ok(());
+/// Reads `CommandEvent`s off a supervised child for as long as it lives,
+/// forwarding output to the frontend and reacting to an unexpected exit by
+/// scheduling a backed-off restart.
+fn supervise(app: tauri::AppHandle, kind: ServiceKind, mut rx: tauri::async_runtime::Receiver<CommandEvent>) {
+    // Taken right after `store_child` kept this child; a later `stop()` or
+    // `kill_and_clear` bumps it, marking this child's exit as requested.
+    let generation = stop_generation(&app, kind);
+    tauri::async_runtime::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(line) | CommandEvent::Stderr(line) => {
+                    app.emit(kind.log_event(), String::from_utf8_lossy(&line).to_string())
+                        .unwrap_or_default();
+                }
+                CommandEvent::Terminated(_) => {
+                    // Stopped on purpose: the slot and flags may already
+                    // belong to a newer child, so leave them alone.
+                    if stop_generation(&app, kind) != generation {
+                        break;
                     }
+                    let (should_run, autorestart, attempts) = app
+                        .try_state::<ServiceRegistry>()
+                        .map(|registry| {
+                            let entry = registry.entry(kind);
+                            if let Ok(mut child) = entry.child.lock() {
+                                *child = None;
+                            }
+                            let should_run = entry.should_run.load(Ordering::SeqCst);
+                            let autorestart = entry.autorestart.load(Ordering::SeqCst);
+                            let attempts = if should_run {
+                                entry.restart_attempts.fetch_add(1, Ordering::SeqCst) + 1
+                            } else {
+                                entry.restart_attempts.load(Ordering::SeqCst)
+                            };
+                            (should_run, autorestart, attempts)
+                        })
+                        .unwrap_or((false, false, 0));
+
+                    kind.set_running(&app, false);
+
+                    if should_run {
+                        app.emit(kind.status_event(), "crashed").unwrap_or_default();
+                        if autorestart {
+                            schedule_restart(app.clone(), kind, attempts);
+                        }
+                    }
+                    break;
                 }
-                return Ok(());
+                _ => {}
+            }
+        }
+    });
+}
+
+fn schedule_restart(app: tauri::AppHandle, kind: ServiceKind, attempts: u32) {
+    let backoff = backoff_delay(attempts);
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(backoff)).await;
+
+        let should_run = app
+            .try_state::<ServiceRegistry>()
+            .map(|registry| registry.entry(kind).should_run.load(Ordering::SeqCst))
+            .unwrap_or(false);
+        if !should_run {
+            return;
+        }
+
+        match kind {
+            ServiceKind::Backend => start_backend_server(app.clone()).await,
+            ServiceKind::Mcp => {
+                let _ = start(&app, kind).await;
+            }
+        }
+
+        // If the restarted process stays healthy for a while, forgive past
+        // crashes so a single flaky run doesn't doom us to the max backoff.
+        tokio::time::sleep(Duration::from_secs(STABLE_WINDOW_SECS)).await;
+        let port = app
+            .try_state::<ServiceRegistry>()
+            .map(|registry| registry.entry(kind).port())
+            .unwrap_or_else(|| kind.default_port());
+        if health(kind, port).await {
+            if let Some(registry) = app.try_state::<ServiceRegistry>() {
+                registry.entry(kind).restart_attempts.store(0, Ordering::SeqCst);
             }
-            Err(_) => continue,
         }
+    });
+}
+
+fn backoff_delay(attempts: u32) -> u64 {
+    let delay = BASE_BACKOFF_SECS.saturating_mul(1 << attempts.saturating_sub(1).min(5));
+    delay.min(MAX_BACKOFF_SECS)
+}
+
+/// Picks which port a service should bind to: the preferred port if it's
+/// free, the preferred port if it's already our own healthy instance (so a
+/// relaunch reuses rather than relocates it), or the next free port in range
+/// if a foreign process has it. Updates the registry with the outcome.
+async fn resolve_port(app: &tauri::AppHandle, kind: ServiceKind) -> u16 {
+    let preferred = app
+        .try_state::<ServiceRegistry>()
+        .map(|registry| registry.entry(kind).port())
+        .unwrap_or_else(|| kind.default_port());
+
+    let port = if port_is_free(preferred).await {
+        preferred
+    } else if health(kind, preferred).await {
+        preferred
+    } else {
+        find_free_port(preferred.wrapping_add(1)).await.unwrap_or(preferred)
+    };
+
+    if let Some(registry) = app.try_state::<ServiceRegistry>() {
+        registry.entry(kind).port.store(port, Ordering::SeqCst);
     }
+    port
+}
 
-    Err("Could not find python3 or python to start MCP server.".to_string())
+/// True if nothing is currently listening on `port` on loopback, i.e. it's
+/// safe for us to bind there.
+async fn port_is_free(port: u16) -> bool {
+    tokio::net::TcpListener::bind(("127.0.0.1", port)).await.is_ok()
 }
 
-pub fn do_stop_mcp(app: &tauri::AppHandle) -> Result<(), String> {
-    if let Some(state) = app.try_state::<Mutex<McpProcess>>() {
-        if let Ok(mut proc) = state.lock() {
-            if let Some(child) = proc.child.take() {
-                child.kill().map_err(|e| e.to_string())?;
-            }
+/// Scans forward from `start` for the first free loopback port, giving up
+/// after `PORT_SCAN_RANGE` attempts.
+async fn find_free_port(start: u16) -> Option<u16> {
+    for offset in 0..PORT_SCAN_RANGE {
+        let port = start.wrapping_add(offset);
+        if port_is_free(port).await {
+            return Some(port);
         }
     }
-    update_mcp_state(app, false);
-    app.emit("mcp-status", "stopped").unwrap_or_default();
-    Ok(())
+    None
 }
 
-async fn check_mcp_port() -> bool {
+async fn health(kind: ServiceKind, port: u16) -> bool {
+    let url = format!("{}{}", kind.base_url(port), kind.health_path());
     match reqwest::Client::new()
-        .get("http://127.0.0.1:8901/sse")
-        .timeout(std::time::Duration::from_secs(2))
+        .get(url)
+        .timeout(Duration::from_secs(2))
         .send()
         .await
     {
@@ -223,11 +541,3 @@ async fn check_mcp_port() -> bool {
         Err(_) => false,
     }
 }
-
-fn update_mcp_state(app: &tauri::AppHandle, running: bool) {
-    if let Some(state) = app.try_state::<Mutex<AppState>>() {
-        if let Ok(mut s) = state.lock() {
-            s.mcp_running = running;
-        }
-    }
-}
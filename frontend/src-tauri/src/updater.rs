@@ -0,0 +1,85 @@
+use tauri::Emitter;
+use tauri_plugin_dialog::DialogExt;
+use tauri_plugin_process::ProcessExt;
+use tauri_plugin_updater::UpdaterExt;
+
+use crate::server;
+
+/// Runs once on startup (the caller delays it a few seconds so the backend
+/// can settle) to check for and optionally apply an update without the user
+/// having to ask.
+pub async fn check_on_startup(app: tauri::AppHandle) {
+    match do_check_for_update(&app).await {
+        Ok(true) => {
+            let answer = app
+                .dialog()
+                .message("A new version of Achilles Vault is available. Update now?")
+                .title("Update available")
+                .buttons(tauri_plugin_dialog::MessageDialogButtons::OkCancel)
+                .blocking_show();
+            if answer {
+                let _ = do_install_update(&app).await;
+            }
+        }
+        Ok(false) => {}
+        Err(_) => {}
+    }
+}
+
+/// Checks the configured release endpoint for a newer version. Emits
+/// `update-status` = `checking`, then `available` or `uptodate`.
+#[tauri::command]
+pub async fn check_for_update(app: tauri::AppHandle) -> Result<bool, String> {
+    do_check_for_update(&app).await
+}
+
+async fn do_check_for_update(app: &tauri::AppHandle) -> Result<bool, String> {
+    app.emit("update-status", "checking").unwrap_or_default();
+
+    let updater = app.updater().map_err(|e| e.to_string())?;
+    match updater.check().await {
+        Ok(Some(_update)) => {
+            app.emit("update-status", "available").unwrap_or_default();
+            Ok(true)
+        }
+        Ok(None) => {
+            app.emit("update-status", "uptodate").unwrap_or_default();
+            Ok(false)
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Downloads and applies the update, then relaunches. Stops the supervised
+/// backend/MCP children first so we don't replace binaries out from under a
+/// running vault process. If the download or install fails, the stopped
+/// services are started again; on success the relaunched app starts them.
+#[tauri::command]
+pub async fn install_update(app: tauri::AppHandle) -> Result<(), String> {
+    do_install_update(&app).await
+}
+
+async fn do_install_update(app: &tauri::AppHandle) -> Result<(), String> {
+    let updater = app.updater().map_err(|e| e.to_string())?;
+    let update = updater
+        .check()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "No update available".to_string())?;
+
+    let _ = server::do_stop_mcp(app);
+    let _ = server::do_stop_server(app);
+
+    app.emit("update-status", "downloading").unwrap_or_default();
+    if let Err(e) = update.download_and_install(|_chunk, _total| {}, || {}).await {
+        // The download/install failed partway through (network drop, disk
+        // full, ...). Bring the services we stopped back up instead of
+        // leaving the vault dead until the user thinks to relaunch it.
+        server::start_backend_server(app.clone()).await;
+        let _ = server::do_start_mcp(app).await;
+        return Err(e.to_string());
+    }
+
+    app.emit("update-status", "ready").unwrap_or_default();
+    app.restart();
+}